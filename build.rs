@@ -0,0 +1,73 @@
+use std::{
+	env, fs,
+	path::Path,
+	process::Command,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Captures the information a flashed board can report back about its own provenance
+/// (which is invaluable once you're juggling more than one board on the bench) into
+/// `$OUT_DIR/built.rs`, which the library's `built` module (in `src/lib.rs`) then `include!`s.
+fn main() {
+	// No `cargo:rerun-if-changed` here: we want Cargo's default "rerun on any package file
+	// change" behavior, so `GIT_DIRTY` and `BUILT_TIME_UTC` stay accurate across
+	// source-only rebuilds instead of going stale between commits.
+
+	let git_commit_hash = Command::new("git")
+		.args(&["rev-parse", "HEAD"])
+		.output()
+		.ok()
+		.filter(|output| output.status.success())
+		.and_then(|output| String::from_utf8(output.stdout).ok())
+		.map(|hash| hash.trim().to_string())
+		.unwrap_or_else(|| "unknown".to_string());
+
+	let git_dirty = Command::new("git")
+		.args(&["status", "--porcelain"])
+		.output()
+		.ok()
+		.filter(|output| output.status.success())
+		.map(|output| !output.stdout.is_empty())
+		.unwrap_or(false);
+
+	let built_time_utc = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.expect("System time is before the Unix epoch.")
+		.as_secs();
+
+	let target = env::var("TARGET").expect("`TARGET` not set.");
+
+	let profile = env::var("PROFILE").expect("`PROFILE` not set.");
+	let pkg_version = env::var("CARGO_PKG_VERSION").expect("`CARGO_PKG_VERSION` not set.");
+
+	let built_rs = format!(
+		r#"
+/// The git commit this binary was built from, or `"unknown"` if it wasn't built inside a git repository.
+pub const GIT_COMMIT_HASH: &str = "{git_commit_hash}";
+
+/// Whether the git working tree had uncommitted changes at build time.
+pub const GIT_DIRTY: bool = {git_dirty};
+
+/// Seconds since the Unix epoch at build time.
+pub const BUILT_TIME_UTC: u64 = {built_time_utc};
+
+/// The crate version ([`CARGO_PKG_VERSION`](https://doc.rust-lang.org/cargo/reference/environment-variables.html)) this binary was built from.
+pub const PKG_VERSION: &str = "{pkg_version}";
+
+/// The cargo profile (`"debug"` or `"release"`) this binary was built with.
+pub const PROFILE: &str = "{profile}";
+
+/// The target triple this binary was built for.
+pub const TARGET: &str = "{target}";
+"#,
+		git_commit_hash = git_commit_hash,
+		git_dirty = git_dirty,
+		built_time_utc = built_time_utc,
+		pkg_version = pkg_version,
+		profile = profile,
+		target = target,
+	);
+
+	let out_dir = env::var("OUT_DIR").expect("`OUT_DIR` not set.");
+	fs::write(Path::new(&out_dir).join("built.rs"), built_rs).expect("Failed to write `built.rs`.");
+}
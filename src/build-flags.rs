@@ -1,3 +1,4 @@
+use std::env;
 use std::process::Command;
 
 macro_rules! bprintln {
@@ -7,15 +8,83 @@ macro_rules! bprintln {
 	};
 }
 
-fn main() {
-	eprintln!("Building Rust crate using Cargo...");
+/// A supported AVR board and the flags needed to cross-build the firmware for it.
+#[derive(Clone, Copy)]
+struct Board {
+	/// The board's identifier, as used in the `BOARDS` env var and in the build progress
+	/// message. The `-l` flag is the same for every board.
+	name: &'static str,
+	/// The `rustc` target-spec JSON name (without the `.json` extension), passed to
+	/// `--target`.
+	target: &'static str,
+	/// The MCU's clock frequency in Hz, reported alongside the board name. Not yet wired
+	/// into the build; add an `F_CPU`-style env var here once firmware code needs to read
+	/// it.
+	cpu_frequency_hz: u64,
+	/// The `-Z build-std` crate set this target needs.
+	build_std: &'static str,
+	/// Any extra linker arguments this board's MCU needs, printed alongside the `-L`/`-l`
+	/// lines.
+	extra_linker_args: &'static [&'static str],
+}
+
+/// The boards this template instance knows how to build for. Add an entry here (and, if
+/// needed, a matching target-spec JSON file) to support another MCU.
+const BOARDS: &[Board] = &[
+	Board {
+		name: "atmega328",
+		target: "avr-unknown-gnu-atmega328",
+		cpu_frequency_hz: 16_000_000,
+		build_std: "core",
+		extra_linker_args: &[],
+	},
+	Board {
+		name: "atmega2560",
+		target: "avr-unknown-gnu-atmega2560",
+		cpu_frequency_hz: 16_000_000,
+		build_std: "core",
+		extra_linker_args: &[],
+	},
+	Board {
+		name: "atmega32u4",
+		target: "avr-unknown-gnu-atmega32u4",
+		cpu_frequency_hz: 16_000_000,
+		build_std: "core",
+		extra_linker_args: &[],
+	},
+];
+
+/// The boards to build, as requested through the `BOARDS` env var (comma-separated board
+/// names), or every known board if it's unset.
+fn requested_boards() -> Vec<Board> {
+	match env::var("BOARDS") {
+		Ok(requested) => requested
+			.split(',')
+			.map(|name| {
+				BOARDS
+					.iter()
+					.copied()
+					.find(|board| board.name == name)
+					.unwrap_or_else(|| panic!("Unknown board `{}`.", name))
+			})
+			.collect(),
+		Err(_) => BOARDS.to_vec(),
+	}
+}
+
+/// Builds `board` in release mode, then prints the `-L`/`-l` linker lines for it.
+fn build(board: Board) {
+	eprintln!(
+		"Building Rust crate for `{}` ({} Hz) using Cargo...",
+		board.name, board.cpu_frequency_hz,
+	);
 	let status = Command::new("cargo")
 		.env("RUST_TARGET_PATH", std::env::current_dir().unwrap())
 		.env("RUST_BACKTRACE", "1")
 		.arg("build")
 		.arg("--release")
-		.args(&["--target", "avr-unknown-gnu-atmega328"])
-		.args(&["-Z", "build-std=core"])
+		.args(&["--target", board.target])
+		.args(&["-Z", &format!("build-std={}", board.build_std)])
 		.status()
 		.expect("Failed to execute command `cargo build`.");
 	assert!(status.success());
@@ -25,7 +94,7 @@ fn main() {
 		dunce::canonicalize(
 			std::env::current_dir()
 				.unwrap()
-				.join("target/avr-unknown-gnu-atmega328/release")
+				.join(format!("target/{}/release", board.target))
 				.canonicalize()
 				.unwrap()
 		)
@@ -34,5 +103,14 @@ fn main() {
 		.to_string()
 		.replace('\\', "/")
 	);
+	for extra_linker_arg in board.extra_linker_args {
+		bprintln!("{}", extra_linker_arg);
+	}
 	bprintln!("-lTODO_CRATE_NAME");
 }
+
+fn main() {
+	for board in requested_boards() {
+		build(board);
+	}
+}
@@ -10,6 +10,12 @@ pub mod readme {
 	doc_comment::doctest!("../README.md");
 }
 
+/// Build-time provenance metadata (git commit, build timestamp, target triple, ...),
+/// generated by `build.rs`. Handy for reporting which build is flashed on a given board.
+pub mod built {
+	include!(concat!(env!("OUT_DIR"), "/built.rs"));
+}
+
 #[no_mangle]
 extern "C" fn setup() {}
 
@@ -0,0 +1,76 @@
+//! Mirrors rustc's tidy `deps` check: every crate in the dependency graph must carry a
+//! permissive license, so a template-derived crate never accidentally pulls in a copyleft
+//! transitive dependency.
+
+use cargo_metadata::MetadataCommand;
+
+/// Individual SPDX license identifiers that are acceptable for a template-derived crate to
+/// depend on.
+const ALLOWED: &[&str] = &[
+	"MIT",
+	"Apache-2.0",
+	"ISC",
+	"Unicode-DFS-2016",
+	"Zlib",
+	"BSD-2-Clause",
+	"BSD-3-Clause",
+	"CC0-1.0",
+];
+
+/// Deliberately-accepted outliers, keyed by `(crate_name, license)`.
+const EXCEPTIONS: &[(&str, &str)] = &[];
+
+/// Splits an SPDX-ish license expression into its `OR`-disjuncts, each itself split into the
+/// `AND`-conjoined atoms that make it up (crates.io also uses `/` as an `OR` separator). A
+/// disjunct is satisfied if every one of its atoms is allowed, and the whole expression is
+/// satisfied if any disjunct is — mirroring "choose one of several options" vs. "must
+/// comply with all of these terms". Parentheses are not interpreted: they stay attached to
+/// the atoms next to them, so such atoms never match `ALLOWED`.
+fn disjuncts(license: &str) -> Vec<Vec<&str>> {
+	license
+		.split('/')
+		.flat_map(|disjunct| disjunct.split(" OR "))
+		.map(|disjunct| disjunct.split(" AND ").map(str::trim).collect())
+		.collect()
+}
+
+#[test]
+fn dependency_licenses() {
+	let metadata = MetadataCommand::new()
+		.exec()
+		.expect("Failed to run `cargo metadata`.");
+
+	for package in &metadata.packages {
+		if metadata.workspace_members.contains(&package.id) {
+			// Workspace members are covered by the crate's own `license` field, not this audit.
+			continue;
+		}
+
+		let license = match &package.license {
+			Some(license) => license,
+			None if package.license_file.is_some() => continue,
+			None => panic!(
+				"Package `{} {}` has neither a `license` nor a `license-file`.",
+				package.name, package.version,
+			),
+		};
+
+		if EXCEPTIONS
+			.iter()
+			.any(|(name, allowed)| *name == package.name && *allowed == license)
+		{
+			continue;
+		}
+
+		let is_allowed = disjuncts(license)
+			.iter()
+			.any(|atoms| atoms.iter().all(|atom| ALLOWED.contains(atom)));
+		if !is_allowed {
+			panic!(
+				"Package `{} {}` has disallowed license `{}`. If this is a deliberate, \
+				 reviewed exception, add it to `EXCEPTIONS` instead.",
+				package.name, package.version, license,
+			);
+		}
+	}
+}
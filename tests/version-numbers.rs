@@ -1,7 +1,52 @@
+use semver::Version;
+
+/// The crate's own version, split so the major, minor and patch components can be
+/// substituted independently of one another.
+struct VersionComponents {
+	major: u64,
+	minor: u64,
+	patch: u64,
+}
+
+impl VersionComponents {
+	// For a pre-1.0 (`0.y.z`) crate, `y` is the breaking component per semver's
+	// initial-development rules, so `{major}.{minor}` still matches on the right boundary.
+	fn major_minor(&self) -> String {
+		format!("{}.{}", self.major, self.minor)
+	}
+}
+
+fn version_components() -> VersionComponents {
+	// `Version::parse` keeps any pre-release and build-metadata suffix in separate fields, so
+	// the numeric components taken here are free of them.
+	let version =
+		Version::parse(env!("CARGO_PKG_VERSION")).expect("Failed to parse `CARGO_PKG_VERSION`.");
+	VersionComponents {
+		major: version.major,
+		minor: version.minor,
+		patch: version.patch,
+	}
+}
+
+/// Wraps [`version_sync::assert_contains_regex`], substituting `{major}`, `{minor}`,
+/// `{patch}` and `{major}.{minor}` in `$regex` with the current crate's version components
+/// before matching `$file`.
+macro_rules! assert_contains_version_components_regex {
+	($file:expr, $regex:expr) => {{
+		let components = version_components();
+		let regex = $regex
+			.replace("{major}.{minor}", &components.major_minor())
+			.replace("{major}", &components.major.to_string())
+			.replace("{minor}", &components.minor.to_string())
+			.replace("{patch}", &components.patch.to_string());
+		version_sync::assert_contains_regex!($file, &regex);
+	}};
+}
+
 #[test]
 fn changelog() {
-	// This will become less useful with patches, so I'm on the lookout for a crate that lets me test major, minor and revision independently.
-	version_sync::assert_contains_regex!("CHANGELOG.md", "^## {version}$");
+	// Independent of the exact patch (or, for a `0.y.z` crate, `z`) that's currently released.
+	assert_contains_version_components_regex!("CHANGELOG.md", r"^## {major}\.{minor}$");
 }
 
 #[test]